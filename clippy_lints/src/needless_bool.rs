@@ -5,7 +5,7 @@
 use rustc::lint::*;
 use rustc::hir::*;
 use syntax::ast::LitKind;
-use syntax::codemap::Spanned;
+use syntax::codemap::{Span, Spanned};
 use utils::{span_lint, span_lint_and_then, snippet, snippet_opt};
 
 /// **What it does:** This lint checks for expressions of the form `if c { true } else { false }` (or vice versa) and suggest using the condition directly.
@@ -49,10 +49,15 @@ impl LateLintPass for NeedlessBool {
     fn check_expr(&mut self, cx: &LateContext, e: &Expr) {
         use self::Expression::*;
         if let ExprIf(ref pred, ref then_block, Some(ref else_expr)) = e.node {
-            let reduce = |hint: &str, not| {
-                let hint = match snippet_opt(cx, pred.span) {
-                    Some(pred_snip) => format!("`{}{}`", not, pred_snip),
-                    None => hint.into(),
+            let reduce = |ret: bool, not: bool, fallback: &str| {
+                let hint = if not {
+                    simple_negate(cx, pred)
+                } else {
+                    snippet_opt(cx, pred.span)
+                };
+                let hint = match hint {
+                    Some(h) => if ret { format!("return {}", h) } else { h },
+                    None => fallback.into(),
                 };
                 span_lint_and_then(cx,
                                    NEEDLESS_BOOL,
@@ -77,14 +82,101 @@ impl LateLintPass for NeedlessBool {
                               e.span,
                               "this if-then-else expression will always return false");
                 }
-                (RetBool(true), RetBool(false)) => reduce("its predicate", "return "),
-                (Bool(true), Bool(false)) => reduce("its predicate", ""),
-                (RetBool(false), RetBool(true)) => reduce("`!` and its predicate", "return !"),
-                (Bool(false), Bool(true)) => reduce("`!` and its predicate", "!"),
+                (RetBool(true), RetBool(false)) => reduce(true, false, "return its predicate"),
+                (Bool(true), Bool(false)) => reduce(false, false, "its predicate"),
+                (RetBool(false), RetBool(true)) => reduce(true, true, "return the negated predicate"),
+                (Bool(false), Bool(true)) => reduce(false, true, "the negated predicate"),
                 _ => (),
             }
         }
     }
+
+    fn check_block(&mut self, cx: &LateContext, block: &Block) {
+        use self::Expression::*;
+        let stmts = &block.stmts;
+        for win in 0..stmts.len().saturating_sub(1) {
+            let if_stmt = if let StmtExpr(ref e, _) = stmts[win].node {
+                e
+            } else {
+                continue;
+            };
+            let (pred, then_block) = if let ExprIf(ref pred, ref then_block, None) = if_stmt.node {
+                (pred, then_block)
+            } else {
+                continue;
+            };
+            let then_val = if let RetBool(value) = fetch_bool_block(then_block) {
+                value
+            } else {
+                continue;
+            };
+            let next_stmt = if let StmtSemi(ref e, _) = stmts[win + 1].node {
+                e
+            } else {
+                continue;
+            };
+            let else_val = if let RetBool(value) = fetch_bool_expr(next_stmt) {
+                value
+            } else {
+                continue;
+            };
+            if then_val == else_val {
+                continue;
+            }
+            let hint = if then_val {
+                snippet_opt(cx, pred.span)
+            } else {
+                simple_negate(cx, pred)
+            };
+            let sugg = match hint {
+                Some(h) => format!("return {}", h),
+                None => if then_val { "return the predicate" } else { "return the negated predicate" }.into(),
+            };
+            // stop at `next_stmt`'s span (the `return ...` expression), not the enclosing
+            // `Stmt`'s span, so the original statement's terminating `;` is left in place
+            let sugg_span = Span {
+                lo: if_stmt.span.lo,
+                hi: next_stmt.span.hi,
+                ..if_stmt.span
+            };
+            span_lint_and_then(cx,
+                               NEEDLESS_BOOL,
+                               sugg_span,
+                               "this if-then statement followed by a return can be reduced to a single return",
+                               |db| {
+                                   db.span_suggestion(sugg_span, "you can reduce it to", sugg);
+                               });
+        }
+    }
+}
+
+/// Produce an expression that is the logical negation of `expr`, rewritten so that the result
+/// is both syntactically valid and precedence-correct, rather than just string-prefixing `!`.
+fn simple_negate(cx: &LateContext, expr: &Expr) -> Option<String> {
+    if let ExprBinary(Spanned { node: op, .. }, ref lhs, ref rhs) = expr.node {
+        let inv_op = match op {
+            BiEq => Some("!="),
+            BiNe => Some("=="),
+            // `!(a < b)` and `a >= b` differ when a float operand is NaN
+            _ if cx.tcx.expr_ty(lhs).is_floating_point() => None,
+            BiLt => Some(">="),
+            BiLe => Some(">"),
+            BiGt => Some("<="),
+            BiGe => Some("<"),
+            _ => None,
+        };
+        if let Some(inv_op) = inv_op {
+            return match (snippet_opt(cx, lhs.span), snippet_opt(cx, rhs.span)) {
+                (Some(lhs_snip), Some(rhs_snip)) => Some(format!("{} {} {}", lhs_snip, inv_op, rhs_snip)),
+                _ => None,
+            };
+        }
+    }
+    if let ExprUnary(UnNot, ref inner) = expr.node {
+        return snippet_opt(cx, inner.span);
+    }
+    // non-comparison binops (`&&`, `||`, `+`, ...) and anything else fall back to parenthesizing
+    snippet_opt(cx, expr.span).map(|snip| format!("!({})", snip))
 }
 
 #[derive(Copy,Clone)]
@@ -99,54 +191,103 @@ impl LintPass for BoolComparison {
 impl LateLintPass for BoolComparison {
     fn check_expr(&mut self, cx: &LateContext, e: &Expr) {
         use self::Expression::*;
-        if let ExprBinary(Spanned { node: BiEq, .. }, ref left_side, ref right_side) = e.node {
-            match (fetch_bool_expr(left_side), fetch_bool_expr(right_side)) {
-                (Bool(true), Other) => {
-                    let hint = snippet(cx, right_side.span, "..").into_owned();
-                    span_lint_and_then(cx,
-                                       BOOL_COMPARISON,
-                                       e.span,
-                                       "equality checks against true are unnecessary",
-                                       |db| {
-                                           db.span_suggestion(e.span, "try simplifying it as shown:", hint);
-                                       });
+        if let ExprBinary(Spanned { node: op, .. }, ref left_side, ref right_side) = e.node {
+            match (op, fetch_bool_expr(left_side), fetch_bool_expr(right_side)) {
+                (BiEq, Other, Bool(true)) => suggest_bool_comparison(cx,
+                                                                      e,
+                                                                      "equality checks against true are unnecessary",
+                                                                      snippet(cx, left_side.span, "..").into_owned()),
+                (BiEq, Bool(true), Other) => suggest_bool_comparison(cx,
+                                                                      e,
+                                                                      "equality checks against true are unnecessary",
+                                                                      snippet(cx, right_side.span, "..").into_owned()),
+                (BiEq, Other, Bool(false)) => {
+                    suggest_bool_comparison(cx,
+                                             e,
+                                             "equality checks against false can be replaced by a negation",
+                                             format!("!{}", snippet(cx, left_side.span, "..")))
+                }
+                (BiEq, Bool(false), Other) => {
+                    suggest_bool_comparison(cx,
+                                             e,
+                                             "equality checks against false can be replaced by a negation",
+                                             format!("!{}", snippet(cx, right_side.span, "..")))
+                }
+                (BiNe, Other, Bool(true)) => {
+                    suggest_bool_comparison(cx,
+                                             e,
+                                             "inequality checks against true can be replaced by a negation",
+                                             format!("!{}", snippet(cx, left_side.span, "..")))
                 }
-                (Other, Bool(true)) => {
-                    let hint = snippet(cx, left_side.span, "..").into_owned();
-                    span_lint_and_then(cx,
-                                       BOOL_COMPARISON,
-                                       e.span,
-                                       "equality checks against true are unnecessary",
-                                       |db| {
-                                           db.span_suggestion(e.span, "try simplifying it as shown:", hint);
-                                       });
+                (BiNe, Bool(true), Other) => {
+                    suggest_bool_comparison(cx,
+                                             e,
+                                             "inequality checks against true can be replaced by a negation",
+                                             format!("!{}", snippet(cx, right_side.span, "..")))
                 }
-                (Bool(false), Other) => {
-                    let hint = format!("!{}", snippet(cx, right_side.span, ".."));
-                    span_lint_and_then(cx,
-                                       BOOL_COMPARISON,
-                                       e.span,
-                                       "equality checks against false can be replaced by a negation",
-                                       |db| {
-                                           db.span_suggestion(e.span, "try simplifying it as shown:", hint);
-                                       });
+                (BiNe, Other, Bool(false)) => suggest_bool_comparison(cx,
+                                                                       e,
+                                                                       "inequality checks against false are unnecessary",
+                                                                       snippet(cx, left_side.span, "..").into_owned()),
+                (BiNe, Bool(false), Other) => suggest_bool_comparison(cx,
+                                                                       e,
+                                                                       "inequality checks against false are unnecessary",
+                                                                       snippet(cx, right_side.span, "..").into_owned()),
+                (BiLt, Other, Bool(true)) |
+                (BiLe, Other, Bool(false)) => {
+                    suggest_bool_comparison(cx,
+                                             e,
+                                             "order comparison against a boolean can be replaced by a negation",
+                                             format!("!{}", snippet(cx, left_side.span, "..")))
                 }
-                (Other, Bool(false)) => {
-                    let hint = format!("!{}", snippet(cx, left_side.span, ".."));
-                    span_lint_and_then(cx,
-                                       BOOL_COMPARISON,
-                                       e.span,
-                                       "equality checks against false can be replaced by a negation",
-                                       |db| {
-                                           db.span_suggestion(e.span, "try simplifying it as shown:", hint);
-                                       });
+                (BiGt, Bool(true), Other) |
+                (BiGe, Bool(false), Other) => {
+                    suggest_bool_comparison(cx,
+                                             e,
+                                             "order comparison against a boolean can be replaced by a negation",
+                                             format!("!{}", snippet(cx, right_side.span, "..")))
                 }
+                (BiGt, Other, Bool(false)) |
+                (BiGe, Other, Bool(true)) => suggest_bool_comparison(cx,
+                                                                      e,
+                                                                      "order comparison against a boolean is unnecessary",
+                                                                      snippet(cx, left_side.span, "..").into_owned()),
+                (BiLt, Bool(false), Other) |
+                (BiLe, Bool(true), Other) => suggest_bool_comparison(cx,
+                                                                      e,
+                                                                      "order comparison against a boolean is unnecessary",
+                                                                      snippet(cx, right_side.span, "..").into_owned()),
+                (BiLt, Other, Bool(false)) |
+                (BiLt, Bool(true), Other) |
+                (BiGt, Other, Bool(true)) |
+                (BiGt, Bool(false), Other) => lint_comparison_always(cx, e, false),
+                (BiLe, Other, Bool(true)) |
+                (BiLe, Bool(false), Other) |
+                (BiGe, Other, Bool(false)) |
+                (BiGe, Bool(true), Other) => lint_comparison_always(cx, e, true),
                 _ => (),
             }
         }
     }
 }
 
+fn suggest_bool_comparison(cx: &LateContext, e: &Expr, message: &str, hint: String) {
+    span_lint_and_then(cx, BOOL_COMPARISON, e.span, message, |db| {
+        db.span_suggestion(e.span, "try simplifying it as shown:", hint);
+    });
+}
+
+fn lint_comparison_always(cx: &LateContext, e: &Expr, always_true: bool) {
+    span_lint(cx,
+              BOOL_COMPARISON,
+              e.span,
+              if always_true {
+                  "this comparison is always true"
+              } else {
+                  "this comparison is always false"
+              });
+}
+
 enum Expression {
     Bool(bool),
     RetBool(bool),
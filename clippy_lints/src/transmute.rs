@@ -2,6 +2,7 @@ use rustc::lint::*;
 use rustc::ty::TypeVariants::{TyRawPtr, TyRef};
 use rustc::ty;
 use rustc::hir::*;
+use syntax::ast::{FloatTy, IntTy, UintTy};
 use utils::{match_def_path, paths, snippet_opt, span_lint, span_lint_and_then};
 
 /// **What it does:** This lint checks for transmutes that can't ever be correct on any architecture
@@ -61,11 +62,92 @@ declare_lint! {
     "transmutes from a pointer to a reference type"
 }
 
+/// **What it does:** This lint checks for transmutes between an integer and a float.
+///
+/// **Why is this bad?** Transmutes are dangerous and error-prone; the `from_bits`/`to_bits`
+/// methods make the bit-reinterpretation explicit and do not require `unsafe`.
+///
+/// **Known problems:** None.
+///
+/// **Example:** `let _: f32 = std::mem::transmute(1_u32);` could be `f32::from_bits(1_u32)`.
+declare_lint! {
+    pub TRANSMUTE_INT_TO_FLOAT,
+    Warn,
+    "transmutes between an integer and a float"
+}
+
+/// **What it does:** This lint checks for transmutes from a `u32` to a `char`.
+///
+/// **Why is this bad?** Not every `u32` is a valid `char`. `char::from_u32` performs the
+/// necessary validity check and returns an `Option<char>`, making it the safer choice.
+///
+/// **Known problems:** None.
+///
+/// **Example:** `let _: char = std::mem::transmute(x);` could be
+/// `let _ = char::from_u32(x).unwrap();`
+declare_lint! {
+    pub TRANSMUTE_INT_TO_CHAR,
+    Warn,
+    "transmutes from an integer to a char"
+}
+
+/// **What it does:** This lint checks for transmutes from a `u8` to a `bool`.
+///
+/// **Why is this bad?** Not every `u8` is a valid `bool`. Transmuting a `u8` outside of
+/// `{0, 1}` into a `bool` is undefined behaviour, so `x != 0` is both clearer and sound.
+///
+/// **Known problems:** None.
+///
+/// **Example:** `let _: bool = std::mem::transmute(x);` could be `let _ = x != 0;`
+declare_lint! {
+    pub TRANSMUTE_INT_TO_BOOL,
+    Warn,
+    "transmutes from an integer to a bool"
+}
+
+/// **What it does:** This lint checks for transmutes between raw pointer types.
+///
+/// **Why is this bad?** Pointer casts via `as` can change the pointee type and mutability of a
+/// raw pointer without invoking `transmute`, so `transmute` only adds noise here.
+///
+/// **Known problems:** None.
+///
+/// **Example:** `let _: *const U = std::mem::transmute(p);` could be `p as *const U`.
+declare_lint! {
+    pub TRANSMUTE_PTR_TO_PTR,
+    Warn,
+    "transmutes from a pointer to a pointer"
+}
+
+/// **What it does:** This lint checks for transmutes from `&[u8]` to `&str`.
+///
+/// **Why is this bad?** Not every byte slice is valid UTF-8. `std::str::from_utf8` performs
+/// the necessary check and returns a `Result`, so the transmute skips validation that
+/// `transmute` alone cannot guarantee.
+///
+/// **Known problems:** None.
+///
+/// **Example:** `let _: &str = std::mem::transmute(b);` could be
+/// `let _ = std::str::from_utf8(b).unwrap();`
+declare_lint! {
+    pub TRANSMUTE_BYTES_TO_STR,
+    Warn,
+    "transmutes from `&[u8]` to `&str`"
+}
+
 pub struct Transmute;
 
 impl LintPass for Transmute {
     fn get_lints(&self) -> LintArray {
-        lint_array![CROSSPOINTER_TRANSMUTE, TRANSMUTE_PTR_TO_REF, USELESS_TRANSMUTE, WRONG_TRANSMUTE]
+        lint_array![CROSSPOINTER_TRANSMUTE,
+                    TRANSMUTE_PTR_TO_REF,
+                    USELESS_TRANSMUTE,
+                    WRONG_TRANSMUTE,
+                    TRANSMUTE_INT_TO_FLOAT,
+                    TRANSMUTE_INT_TO_CHAR,
+                    TRANSMUTE_INT_TO_BOOL,
+                    TRANSMUTE_PTR_TO_PTR,
+                    TRANSMUTE_BYTES_TO_STR]
     }
 }
 
@@ -140,6 +222,72 @@ impl LateLintPass for Transmute {
                                      from_ty,
                                      to_ty),
                         ),
+                        (&ty::TyUint(UintTy::U32), &ty::TyFloat(FloatTy::F32)) |
+                        (&ty::TyInt(IntTy::I32), &ty::TyFloat(FloatTy::F32)) => span_lint_and_then(
+                            cx,
+                            TRANSMUTE_INT_TO_FLOAT,
+                            e.span,
+                            &format!("transmute from a `{}` to a `{}`", from_ty, to_ty),
+                            |db| {
+                                if let Some(arg) = snippet_opt(cx, args[0].span) {
+                                    db.span_suggestion(e.span, "consider using", format!("f32::from_bits({})", arg));
+                                }
+                            },
+                        ),
+                        (&ty::TyUint(UintTy::U64), &ty::TyFloat(FloatTy::F64)) |
+                        (&ty::TyInt(IntTy::I64), &ty::TyFloat(FloatTy::F64)) => span_lint_and_then(
+                            cx,
+                            TRANSMUTE_INT_TO_FLOAT,
+                            e.span,
+                            &format!("transmute from a `{}` to a `{}`", from_ty, to_ty),
+                            |db| {
+                                if let Some(arg) = snippet_opt(cx, args[0].span) {
+                                    db.span_suggestion(e.span, "consider using", format!("f64::from_bits({})", arg));
+                                }
+                            },
+                        ),
+                        (&ty::TyFloat(FloatTy::F32), &ty::TyUint(UintTy::U32)) |
+                        (&ty::TyFloat(FloatTy::F32), &ty::TyInt(IntTy::I32)) |
+                        (&ty::TyFloat(FloatTy::F64), &ty::TyUint(UintTy::U64)) |
+                        (&ty::TyFloat(FloatTy::F64), &ty::TyInt(IntTy::I64)) => span_lint_and_then(
+                            cx,
+                            TRANSMUTE_INT_TO_FLOAT,
+                            e.span,
+                            &format!("transmute from a `{}` to a `{}`", from_ty, to_ty),
+                            |db| {
+                                if let Some(arg) = snippet_opt(cx, args[0].span) {
+                                    db.span_suggestion(e.span, "consider using", format!("{}.to_bits()", arg));
+                                }
+                            },
+                        ),
+                        (&ty::TyUint(UintTy::U32), &ty::TyChar) => span_lint_and_then(
+                            cx,
+                            TRANSMUTE_INT_TO_CHAR,
+                            e.span,
+                            &format!("transmute from a `{}` to a `char`", from_ty),
+                            |db| {
+                                if let Some(arg) = snippet_opt(cx, args[0].span) {
+                                    db.span_suggestion(e.span,
+                                                        "consider using",
+                                                        format!("char::from_u32({}).unwrap()", arg));
+                                    db.span_note(e.span, "not all u32 values are valid unicode scalar values");
+                                }
+                            },
+                        ),
+                        (&ty::TyUint(UintTy::U8), &ty::TyBool) => span_lint_and_then(
+                            cx,
+                            TRANSMUTE_INT_TO_BOOL,
+                            e.span,
+                            &format!("transmute from a `{}` to a `bool`", from_ty),
+                            |db| {
+                                if let Some(arg) = snippet_opt(cx, args[0].span) {
+                                    db.span_suggestion(e.span, "consider using", format!("{} != 0", arg));
+                                    db.span_note(e.span,
+                                                 "transmuting a u8 outside of {0, 1} into a bool is undefined \
+                                                  behaviour");
+                                }
+                            },
+                        ),
                         (&TyRawPtr(from_pty), &TyRef(_, to_rty)) => span_lint_and_then(
                             cx,
                             TRANSMUTE_PTR_TO_REF,
@@ -172,6 +320,40 @@ impl LateLintPass for Transmute {
                                 }
                             },
                         ),
+                        (&TyRawPtr(_), &TyRawPtr(_)) => span_lint_and_then(
+                            cx,
+                            TRANSMUTE_PTR_TO_PTR,
+                            e.span,
+                            "transmute from a pointer to a pointer",
+                            |db| {
+                                if let Some(arg) = snippet_opt(cx, args[0].span) {
+                                    db.span_suggestion(e.span, "try", format!("{} as {}", arg, to_ty));
+                                }
+                            },
+                        ),
+                        (&TyRef(_, from_mt), &TyRef(_, to_mt)) => {
+                            if let (&ty::TySlice(slice_ty), &ty::TyStr) = (&from_mt.ty.sty, &to_mt.ty.sty) {
+                                if let ty::TyUint(UintTy::U8) = slice_ty.sty {
+                                    span_lint_and_then(
+                                        cx,
+                                        TRANSMUTE_BYTES_TO_STR,
+                                        e.span,
+                                        &format!("transmute from a `{}` to a `{}`", from_ty, to_ty),
+                                        |db| {
+                                            if let Some(arg) = snippet_opt(cx, args[0].span) {
+                                                db.span_suggestion(e.span,
+                                                                    "consider using",
+                                                                    format!("std::str::from_utf8({}).unwrap()", arg));
+                                                db.span_note(e.span,
+                                                             "this transmute skips the UTF-8 validity check that \
+                                                              `from_utf8` performs");
+                                            }
+                                        },
+                                    );
+                                }
+                            }
+                            return;
+                        }
                         _ => return,
                     };
                 }